@@ -12,6 +12,7 @@ use std::time::{SystemTime, UNIX_EPOCH};
 use std::result::Result;
 use std::io::Error;
 
+#[cfg(windows)]
 use super::windows_sys::{WinFileTime, WinSystemTime, WinTimeZoneInfo};
 
 use super::{FixedOffset, Local};
@@ -23,9 +24,10 @@ pub(super) fn now() -> DateTime<Local> {
     datetime.single().expect("invalid time")
 }
 
-/// Converts a local `NaiveDateTime` to the `time::Timespec`.
-pub(super) fn naive_to_local(d: &NaiveDateTime, local: bool) -> LocalResult<DateTime<Local>> {
-    let tm = Tm {
+/// Builds a broken-down `Tm` from a `NaiveDateTime`, leaving the fields the
+/// conversion paths ignore (`tm_wday`, `tm_yday`, offset/dst flags) unset.
+fn tm_from_naive(d: &NaiveDateTime) -> Tm {
+    Tm {
         tm_sec: d.second() as i32,
         tm_min: d.minute() as i32,
         tm_hour: d.hour() as i32,
@@ -35,36 +37,119 @@ pub(super) fn naive_to_local(d: &NaiveDateTime, local: bool) -> LocalResult<Date
         tm_wday: 0,                // to_local ignores this
         tm_yday: 0,                // and this
         tm_isdst: -1,
-        // This seems pretty fake?
-        tm_utcoff: i32::from(local),
+        tm_utcoff: 0,
         // do not set this, OS APIs are heavily inconsistent in terms of leap second handling
         tm_nsec: 0,
+    }
+}
+
+/// Converts a `NaiveDateTime` to a `DateTime<Local>`, reading it as local time
+/// when `local` is true and as UTC otherwise.
+pub(super) fn naive_to_local(d: &NaiveDateTime, local: bool) -> LocalResult<DateTime<Local>> {
+    // Without the Win32 timezone APIs local time is treated as UTC, so the
+    // disambiguation path below is never taken.
+    #[cfg(not(windows))]
+    let local = {
+        let _ = local;
+        false
     };
 
-    let spec = Timespec {
-        sec: match local {
-            false => {
-                match tm.utc_to_time() {
-                    Ok(sec) => sec,
-                    Err(_) => return LocalResult::None,
+    if !local {
+        // `d` is already expressed in UTC, so there is nothing to disambiguate.
+        let tm = tm_from_naive(d);
+        let spec = Timespec {
+            sec: match tm.utc_to_time() {
+                Ok(sec) => sec,
+                Err(_) => return LocalResult::None,
+            },
+            nsec: tm.tm_nsec,
+        };
+
+        // Adjust for leap seconds
+        let mut tm = spec.local();
+        assert_eq!(tm.tm_nsec, 0);
+        tm.tm_nsec = d.nanosecond() as i32;
+
+        return tm_to_datetime(tm);
+    }
+
+    // `d` is a local time that may not exist (spring-forward gap) or may map to
+    // two distinct instants (fall-back overlap). Enumerate the candidate
+    // offsets of the active timezone ourselves instead of trusting the single
+    // value `TzSpecificLocalTimeToSystemTime` reports.
+    #[cfg(windows)]
+    {
+        // `GetTimeZoneInformationForYear` takes a `u16` year; chrono permits
+        // years well outside that range, for which there is no meaningful
+        // kernel query, so bail rather than truncating into a garbage year.
+        let year = match u16::try_from(d.year()) {
+            Ok(year) => year,
+            Err(_) => return LocalResult::None,
+        };
+        let tz = match WinTimeZoneInfo::for_year(year) {
+            Ok(tz) => tz,
+            Err(_) => return LocalResult::None,
+        };
+        let candidate_offsets =
+            [-60 * (tz.bias() + tz.standard_bias()), -60 * (tz.bias() + tz.daylight_bias())];
+
+        let mut matches: Vec<DateTime<Local>> = Vec::new();
+        for offset_secs in candidate_offsets {
+            if let Some(dt) = resolve_candidate(d, offset_secs, &tz) {
+                // Standard and daylight biases coincide for zones without DST,
+                // so drop any candidate that maps to an instant we already found.
+                if !matches.iter().any(|existing| existing.naive_utc() == dt.naive_utc()) {
+                    matches.push(dt);
                 }
             }
-            true => {
-                match tm.local_to_time() {
-                    Ok(sec) => sec,
-                    Err(_) => return LocalResult::None,
-                }
+        }
+
+        match matches.len() {
+            0 => LocalResult::None,
+            1 => LocalResult::Single(matches[0]),
+            _ => {
+                matches.sort_by_key(|dt| dt.naive_utc());
+                LocalResult::Ambiguous(matches[0], matches[1])
             }
-        },
-        nsec: tm.tm_nsec,
-    };
+        }
+    }
 
-    // Adjust for leap seconds
-    let mut tm = spec.local();
-    assert_eq!(tm.tm_nsec, 0);
-    tm.tm_nsec = d.nanosecond() as i32;
+    // The non-Windows fallback only ever reaches the `!local` branch above.
+    #[cfg(not(windows))]
+    unreachable!("local time is always treated as UTC without the Win32 APIs")
+}
 
-    tm_to_datetime(tm)
+/// Tests whether interpreting `d` with the fixed offset `offset_secs` yields a
+/// self-consistent local time, i.e. converting the resulting UTC instant back
+/// to local time through the OS reproduces `d`. Returns the disambiguated
+/// `DateTime` when it does.
+///
+/// The round-trip is performed with the same per-year `tz` the candidate
+/// offsets were read from, so selection and validation share one rule set and
+/// historical dates whose transition dates differ from today's disambiguate
+/// against the transitions that were actually in force that year.
+#[cfg(windows)]
+fn resolve_candidate(
+    d: &NaiveDateTime,
+    offset_secs: i32,
+    tz: &WinTimeZoneInfo,
+) -> Option<DateTime<Local>> {
+    let offset = FixedOffset::east_opt(offset_secs)?;
+    let utc = *d - offset; // the UTC instant this offset implies
+
+    // Round-trip the candidate UTC instant through the OS and confirm it lands
+    // back on the original broken-down local time.
+    let utc_sys = tm_from_naive(&utc).as_system_time();
+    let local = utc_sys.as_time_zone_specific_with(tz).ok()?;
+    let inner = local.inner();
+    let round_trips = i32::from(inner.wYear) == d.year()
+        && u32::from(inner.wMonth) == d.month()
+        && u32::from(inner.wDay) == d.day()
+        && u32::from(inner.wHour) == d.hour()
+        && u32::from(inner.wMinute) == d.minute()
+        && u32::from(inner.wSecond) == d.second();
+
+    round_trips.then(|| DateTime::from_utc(utc, offset))
 }
 
 
@@ -180,6 +265,7 @@ impl Tm {
     }
 
     // TODO: consider changing to update_ from set_
+    #[cfg(windows)]
     pub(crate) fn update_from_seconds(&mut self, sec: i64) -> Result<(), Error> {
             let filetime = WinFileTime::from_seconds(sec);
             let utc = filetime.as_system_time()?;
@@ -189,7 +275,11 @@ impl Tm {
             let local_filetime = local.as_file_time()?;
             let local_sec = local_filetime.as_unix_seconds();
 
-            let tz = WinTimeZoneInfo::new()?;
+            // Read the biases for the year of the instant being converted rather
+            // than the currently active rule set for the standard/daylight check
+            // below. The conversion above goes through `as_time_zone_specific`,
+            // which applies the active rules, not this year's.
+            let tz = WinTimeZoneInfo::for_year(local.inner().wYear)?;
 
             // SystemTimeToTzSpecificLocalTime already applied the biases so
             // check if it non standard
@@ -198,6 +288,17 @@ impl Tm {
             Ok(())
         }
 
+    /// Pure-Rust fallback for targets without the Win32 time APIs: local time
+    /// is treated as UTC, so the conversion is a plain epoch-seconds breakdown.
+    #[cfg(not(windows))]
+    pub(crate) fn update_from_seconds(&mut self, sec: i64) -> Result<(), Error> {
+        time_to_tm(sec, self);
+        self.tm_utcoff = 0;
+        self.tm_isdst = 0;
+        Ok(())
+    }
+
+    #[cfg(windows)]
     pub(crate) fn update_from_system_time(&mut self, sys: &WinSystemTime) {
         self.tm_sec = sys.inner().wSecond as i32;
         self.tm_min = sys.inner().wMinute as i32;
@@ -209,6 +310,7 @@ impl Tm {
         self.tm_yday = yday(self.tm_year, self.tm_mon + 1, self.tm_mday);
     }
 
+    #[cfg(windows)]
     pub(crate) fn as_system_time(&self) -> WinSystemTime {
         let mut sys = WinSystemTime::new();
         sys.mut_inner().wSecond = self.tm_sec as u16;
@@ -221,20 +323,20 @@ impl Tm {
         sys
     }
 
+    #[cfg(windows)]
     fn utc_to_time(&self) -> Result<i64, Error> {
         let sys_time = self.as_system_time();
         let filetime = sys_time.as_file_time()?;
         Ok(filetime.as_unix_seconds())
     }
 
-    fn local_to_time(&self) -> Result<i64, Error> {
-        let sys_time = self.as_system_time();
-        let utc = WinSystemTime::from_local_time(&sys_time)?;
-        let filetime = utc.as_file_time()?;
-        Ok(filetime.as_unix_seconds())
+    #[cfg(not(windows))]
+    fn utc_to_time(&self) -> Result<i64, Error> {
+        Ok(tm_to_time(self))
     }
 }
 
+#[cfg(windows)]
 fn yday(year: i32, month: i32, day: i32) -> i32 {
     let leap = if month > 2 {
         if year % 4 == 0 {
@@ -250,3 +352,77 @@ fn yday(year: i32, month: i32, day: i32) -> i32 {
     (month - 1) * 30 + month / 2 + (day - 1) - leap + july
 }
 
+#[cfg(not(windows))]
+fn is_leap_year(year: i64) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+/// Breaks `ts` (seconds since the Unix epoch) down into a `Tm`, treating the
+/// value as UTC. This is the pure-Rust stand-in for the Win32 conversion used
+/// on targets that have no timezone syscalls.
+#[cfg(not(windows))]
+fn time_to_tm(ts: i64, tm: &mut Tm) {
+    let dayclock = ts.rem_euclid(86400);
+    let mut dayno = ts.div_euclid(86400);
+
+    tm.tm_sec = (dayclock % 60) as i32;
+    tm.tm_min = ((dayclock % 3600) / 60) as i32;
+    tm.tm_hour = (dayclock / 3600) as i32;
+    // The epoch (1970-01-01) fell on a Thursday.
+    tm.tm_wday = (dayno + 4).rem_euclid(7) as i32;
+
+    // Walk to the year containing `dayno`, stepping backwards for pre-1970
+    // (negative) day counts and forwards otherwise, leaving `dayno` normalized
+    // into `[0, days_in_year)`.
+    let mut year = 1970_i64;
+    while dayno < 0 {
+        year -= 1;
+        dayno += if is_leap_year(year) { 366 } else { 365 };
+    }
+    loop {
+        let days_in_year = if is_leap_year(year) { 366 } else { 365 };
+        if dayno < days_in_year {
+            break;
+        }
+        dayno -= days_in_year;
+        year += 1;
+    }
+    tm.tm_year = (year - 1900) as i32;
+    tm.tm_yday = dayno as i32;
+
+    let months: [i64; 12] = if is_leap_year(year) {
+        [31, 29, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31]
+    } else {
+        [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31]
+    };
+    let mut month = 0;
+    while dayno >= months[month] {
+        dayno -= months[month];
+        month += 1;
+    }
+    tm.tm_mon = month as i32;
+    tm.tm_mday = (dayno + 1) as i32;
+}
+
+/// Inverse of [`time_to_tm`]: folds a `Tm` back into seconds since the Unix
+/// epoch via the days-from-civil formula, again treating the time as UTC.
+#[cfg(not(windows))]
+fn tm_to_time(tm: &Tm) -> i64 {
+    let mut y = i64::from(tm.tm_year) + 1900;
+    let mut m = i64::from(tm.tm_mon) + 1;
+    let d = i64::from(tm.tm_mday);
+    let h = i64::from(tm.tm_hour);
+    let mi = i64::from(tm.tm_min);
+    let s = i64::from(tm.tm_sec);
+
+    if m <= 2 {
+        y -= 1;
+        m += 12;
+    }
+
+    (365 * y + y / 4 - y / 100 + y / 400 + 3 * (m + 1) / 5 + 30 * m + d - 719561) * 86400
+        + 3600 * h
+        + 60 * mi
+        + s
+}
+
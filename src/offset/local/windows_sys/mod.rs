@@ -1,14 +1,17 @@
+use std::collections::HashMap;
 use std::io::Error;
 use std::ptr;
 use std::result::Result;
 use std::fmt;
+use std::mem;
+use std::sync::Mutex;
 
 use windows_sys::Win32::{
     Foundation::{FILETIME, SYSTEMTIME},
     System::Time::{
-        FileTimeToSystemTime, GetTimeZoneInformation, SystemTimeToFileTime, 
-        SystemTimeToTzSpecificLocalTime, TzSpecificLocalTimeToSystemTime, 
-        TIME_ZONE_INFORMATION, TIME_ZONE_ID_INVALID,
+        DYNAMIC_TIME_ZONE_INFORMATION, FileTimeToSystemTime, GetDynamicTimeZoneInformation,
+        GetTimeZoneInformationForYear, SystemTimeToFileTime, SystemTimeToTzSpecificLocalTime,
+        TIME_ZONE_ID_INVALID, TIME_ZONE_INFORMATION,
     },
 };
 
@@ -68,18 +71,26 @@ impl WinSystemTime {
         &mut self.inner
     }
 
-    pub(crate) fn from_local_time(local: &WinSystemTime) -> Result<WinSystemTime, Error> {
-        let mut sys_time = Self::new();
-        unsafe { call!(TzSpecificLocalTimeToSystemTime(ptr::null(), &local.inner(), sys_time.mut_inner())) };
-        Ok(sys_time)
-    }
-
     pub(crate) fn as_time_zone_specific(&self) -> Result<WinSystemTime, Error> {
         let mut local = WinSystemTime::new();
         unsafe { call!(SystemTimeToTzSpecificLocalTime(ptr::null(), &self.inner(), local.mut_inner())) };
         Ok(local)
     }
 
+    /// Like [`as_time_zone_specific`](Self::as_time_zone_specific), but uses the
+    /// explicit `tz` rules rather than the currently active ones, so the
+    /// conversion is consistent with the per-year biases they were read from.
+    pub(crate) fn as_time_zone_specific_with(
+        &self,
+        tz: &WinTimeZoneInfo,
+    ) -> Result<WinSystemTime, Error> {
+        let mut local = WinSystemTime::new();
+        unsafe {
+            call!(SystemTimeToTzSpecificLocalTime(tz.as_ptr(), &self.inner(), local.mut_inner()))
+        };
+        Ok(local)
+    }
+
     pub(crate) fn as_file_time(&self) -> Result<WinFileTime, Error> {
         let mut filetime = WinFileTime::new();
         unsafe { call!( SystemTimeToFileTime(&self.inner(), filetime.mut_inner())) };
@@ -136,7 +147,22 @@ pub(crate) struct WinTimeZoneInfo {
 }
 
 impl WinTimeZoneInfo {
-    pub(crate) fn new() -> Result<Self, Error> {
+    /// Queries the timezone rules that were in effect during `year`.
+    ///
+    /// Unlike a bare `GetTimeZoneInformation`, which only reports the currently
+    /// active DST rule set, this resolves the standard/daylight biases that
+    /// applied in the given year so historical dates land on the correct
+    /// offset. The per-year result is memoized in a process-level cache so that
+    /// tight conversion loops reuse the biases instead of repeating the
+    /// `GetTimeZoneInformationForYear` query; each call still reads the dynamic
+    /// timezone key name to validate the cache. The cache is tagged with the current dynamic timezone key name,
+    /// so it is dropped automatically when the host timezone changes and the
+    /// next call re-queries the kernel with the new rules.
+    pub(crate) fn for_year(year: u16) -> Result<Self, Error> {
+        if let Some(inner) = cached_tz_info(year) {
+            return Ok(Self { inner });
+        }
+
         let mut tz = TIME_ZONE_INFORMATION {
             Bias: 0,
             StandardName: [0_u16; 32],
@@ -147,18 +173,18 @@ impl WinTimeZoneInfo {
             DaylightBias: 0,
         };
 
-        unsafe {
-            let result = GetTimeZoneInformation(&mut tz);
-            if result == TIME_ZONE_ID_INVALID {
-                return Err(Error::last_os_error());
-            }
-        }
+        unsafe { call!(GetTimeZoneInformationForYear(year, ptr::null(), &mut tz)) };
 
+        store_tz_info(year, tz);
         Ok(Self {
             inner: tz,
         })
     }
 
+    pub(crate) const fn as_ptr(&self) -> *const TIME_ZONE_INFORMATION {
+        &self.inner
+    }
+
     pub(crate) const fn bias(&self) -> i32 {
         self.inner.Bias
     }
@@ -166,4 +192,61 @@ impl WinTimeZoneInfo {
     pub(crate) const fn standard_bias(&self) -> i32 {
         self.inner.StandardBias
     }
-}
\ No newline at end of file
+
+    pub(crate) const fn daylight_bias(&self) -> i32 {
+        self.inner.DaylightBias
+    }
+}
+
+/// Process-level cache of per-year `TIME_ZONE_INFORMATION`, tagged with the
+/// dynamic timezone key name it was captured under. When the host timezone
+/// changes the key name changes too, so the whole map is discarded and the
+/// biases are re-queried rather than served stale.
+struct TzInfoCache {
+    key: [u16; 128],
+    by_year: HashMap<u16, TIME_ZONE_INFORMATION>,
+}
+
+/// Populated lazily so the common case of repeated conversions within the same
+/// year (and timezone) avoids the `GetTimeZoneInformationForYear` syscall.
+static TZ_INFO_CACHE: Mutex<Option<TzInfoCache>> = Mutex::new(None);
+
+/// Reads the current dynamic timezone key name, used as the cache's validity
+/// token. Returns `None` when the information is unavailable, which simply
+/// disables caching for that call rather than risking a stale hit.
+fn current_tz_key() -> Option<[u16; 128]> {
+    let mut dtzi: DYNAMIC_TIME_ZONE_INFORMATION = unsafe { mem::zeroed() };
+    if unsafe { GetDynamicTimeZoneInformation(&mut dtzi) } == TIME_ZONE_ID_INVALID {
+        return None;
+    }
+    Some(dtzi.TimeZoneKeyName)
+}
+
+fn cached_tz_info(year: u16) -> Option<TIME_ZONE_INFORMATION> {
+    let key = current_tz_key()?;
+    let mut cache = TZ_INFO_CACHE.lock().ok()?;
+    match cache.as_ref() {
+        Some(c) if c.key == key => c.by_year.get(&year).copied(),
+        // No entry, or the host timezone changed since we cached: drop it.
+        _ => {
+            *cache = None;
+            None
+        }
+    }
+}
+
+fn store_tz_info(year: u16, tz: TIME_ZONE_INFORMATION) {
+    let key = match current_tz_key() {
+        Some(key) => key,
+        None => return,
+    };
+    if let Ok(mut cache) = TZ_INFO_CACHE.lock() {
+        let fresh = matches!(cache.as_ref(), Some(c) if c.key == key);
+        if !fresh {
+            *cache = Some(TzInfoCache { key, by_year: HashMap::new() });
+        }
+        if let Some(c) = cache.as_mut() {
+            c.by_year.insert(year, tz);
+        }
+    }
+}